@@ -2,6 +2,7 @@ import { app, BrowserWindow, ipcMain, shell, nativeImage } from 'electron';
 import { autoUpdater } from 'electron-updater';
 import serve from 'electron-serve';
 import path from 'path';
+import fs from 'fs';
 
 const isProd = app.isPackaged;
 const loadURL = serve({
@@ -70,9 +71,51 @@ const createMainWindow = async (): Promise<BrowserWindow> => {
   return window;
 };
 
+type UpdateChannel = 'stable' | 'beta';
+
+const isUpdateChannel = (value: unknown): value is UpdateChannel => value === 'stable' || value === 'beta';
+
+interface Settings {
+  updateChannel: UpdateChannel;
+  autoInstall: boolean;
+}
+
+const defaultSettings: Settings = {
+  updateChannel: 'stable',
+  autoInstall: true,
+};
+
+const getSettingsPath = () => path.join(app.getPath('userData'), 'settings.json');
+
+const loadSettings = (): Settings => {
+  try {
+    const saved = JSON.parse(fs.readFileSync(getSettingsPath(), 'utf-8'));
+    if (saved === null || typeof saved !== 'object' || Array.isArray(saved)) {
+      return { ...defaultSettings };
+    }
+
+    return {
+      updateChannel: isUpdateChannel(saved.updateChannel) ? saved.updateChannel : defaultSettings.updateChannel,
+      autoInstall: typeof saved.autoInstall === 'boolean' ? saved.autoInstall : defaultSettings.autoInstall,
+    };
+  } catch (error) {
+    return { ...defaultSettings };
+  }
+};
+
+const saveSettings = (next: Settings) => {
+  const settingsPath = getSettingsPath();
+  const tempPath = `${settingsPath}.tmp`;
+
+  fs.writeFileSync(tempPath, JSON.stringify(next, null, 2));
+  fs.renameSync(tempPath, settingsPath);
+};
+
+let settings = loadSettings();
+
 autoUpdater.autoDownload = true;
 autoUpdater.autoInstallOnAppQuit = true;
-autoUpdater.allowPrerelease = false;
+autoUpdater.allowPrerelease = settings.updateChannel === 'beta';
 autoUpdater.allowDowngrade = false;
 
 if (app.isPackaged) {
@@ -114,6 +157,10 @@ autoUpdater.on('update-downloaded', (info) => {
   }
 
   setTimeout(() => {
+    if (!settings.autoInstall) {
+      return;
+    }
+
     isQuitting = true;
     autoUpdater.quitAndInstall(true, true);
   }, 3000);
@@ -152,6 +199,44 @@ autoUpdater.on('error', (err) => {
     }
   });
 
+  ipcMain.handle('get-settings', () => {
+    return { ...settings };
+  });
+
+  ipcMain.handle('update-settings', async (_event, patch: Partial<Settings>) => {
+    if (patch === null || typeof patch !== 'object' || Array.isArray(patch)) {
+      return { success: false, error: 'Invalid settings' };
+    }
+
+    if (patch.updateChannel !== undefined && !isUpdateChannel(patch.updateChannel)) {
+      return { success: false, error: `Unknown update channel: ${patch.updateChannel}` };
+    }
+
+    if (patch.autoInstall !== undefined && typeof patch.autoInstall !== 'boolean') {
+      return { success: false, error: `Invalid auto install value: ${patch.autoInstall}` };
+    }
+
+    const next: Settings = {
+      updateChannel: patch.updateChannel ?? settings.updateChannel,
+      autoInstall: patch.autoInstall ?? settings.autoInstall,
+    };
+
+    try {
+      saveSettings(next);
+    } catch (error: any) {
+      return { success: false, error: error.message };
+    }
+
+    settings = next;
+    autoUpdater.allowPrerelease = settings.updateChannel === 'beta';
+
+    if (mainWindow) {
+      mainWindow.webContents.send('settings-changed', { ...settings });
+    }
+
+    return { success: true, settings: { ...settings } };
+  });
+
   ipcMain.on('install-update', () => {
     isQuitting = true;
     autoUpdater.quitAndInstall(true, true);
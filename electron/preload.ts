@@ -2,6 +2,10 @@ import { contextBridge, ipcRenderer } from 'electron';
 
 contextBridge.exposeInMainWorld('electronAPI', {
   getAppVersion: () => ipcRenderer.invoke('get-app-version'),
+  getSettings: () => ipcRenderer.invoke('get-settings'),
+  updateSettings: (patch: Record<string, unknown>) => ipcRenderer.invoke('update-settings', patch),
+  onSettingsChanged: (callback: (settings: any) => void) => ipcRenderer.on('settings-changed', (_event, settings) => callback(settings)),
+  removeSettingsListeners: () => ipcRenderer.removeAllListeners('settings-changed'),
 
   checkForUpdates: () => ipcRenderer.invoke('check-for-updates'),
   downloadUpdate: () => ipcRenderer.invoke('download-update'),
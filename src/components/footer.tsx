@@ -1,12 +1,23 @@
 'use client';
 
-import { Github } from 'lucide-react';
+import { Github, RotateCw, Zap, ZapOff } from 'lucide-react';
 import { Button } from '@/components/ui/button';
 import { useState, useEffect } from 'react';
 import { useElectronUpdater } from '@/hooks/useElectronUpdater';
 
 export default function Footer() {
-  const { currentVersion, openExternal, isElectron } = useElectronUpdater();
+  const {
+    currentVersion,
+    openExternal,
+    isElectron,
+    updateChannel,
+    setUpdateChannel,
+    autoInstall,
+    setAutoInstall,
+    updateDownloaded,
+    updateInfo,
+    installUpdate,
+  } = useElectronUpdater();
   const [version, setVersion] = useState('');
 
   useEffect(() => {
@@ -25,12 +36,53 @@ export default function Footer() {
     }
   };
 
+  const handleChannelClick = async () => {
+    await setUpdateChannel(updateChannel === 'beta' ? 'stable' : 'beta');
+  };
+
+  const handleAutoInstallClick = async () => {
+    await setAutoInstall(!autoInstall);
+  };
+
   return (
     <footer className="fixed bottom-3 left-3 z-50">
       <div className="bg-background/90 backdrop-blur-sm border border-border/50 rounded-md px-2.5 py-1.5 shadow-md flex items-center gap-2">
         <p className="text-[10px] text-muted-foreground font-medium">
           {version || '1.0.0'}
         </p>
+        {isElectron && (
+          <>
+            <div className="w-px h-3 bg-border/60" />
+            <Button
+              variant="ghost"
+              onClick={handleChannelClick}
+              className="h-5 px-1.5 text-[10px] text-muted-foreground font-medium hover:bg-accent/50 transition-colors"
+              title="Update channel (click to switch)"
+            >
+              {updateChannel}
+            </Button>
+            <Button
+              variant="ghost"
+              size="icon"
+              onClick={handleAutoInstallClick}
+              className="h-5 w-5 hover:bg-accent/50 transition-colors"
+              title={autoInstall ? 'Auto-install updates: on' : 'Auto-install updates: off'}
+            >
+              {autoInstall ? <Zap className="h-2 w-2" /> : <ZapOff className="h-2 w-2" />}
+            </Button>
+            {updateDownloaded && (
+              <Button
+                variant="ghost"
+                onClick={installUpdate}
+                className="h-5 px-1.5 gap-1 text-[10px] text-primary font-medium hover:bg-accent/50 transition-colors"
+                title="Restart to install the downloaded update"
+              >
+                <RotateCw className="h-2 w-2" />
+                {updateInfo?.version ? `Restart to update ${updateInfo.version}` : 'Restart to update'}
+              </Button>
+            )}
+          </>
+        )}
         <div className="w-px h-3 bg-border/60" />
         <Button
           variant="ghost"
@@ -1,39 +1,6 @@
 import { useEffect, useState, useCallback } from 'react';
 
-interface UpdateInfo {
-  version: string;
-  releaseDate: string;
-  releaseNotes?: string;
-}
-
-interface DownloadProgress {
-  bytesPerSecond: number;
-  percent: number;
-  transferred: number;
-  total: number;
-}
-
-interface ElectronAPI {
-  checkForUpdates: () => Promise<{ success: boolean; updateInfo?: UpdateInfo; error?: string }>;
-  downloadUpdate: () => Promise<{ success: boolean; error?: string }>;
-  installUpdate: () => void;
-  getAppVersion: () => Promise<string>;
-  onUpdateChecking: (callback: () => void) => void;
-  onUpdateAvailable: (callback: (info: UpdateInfo) => void) => void;
-  onUpdateNotAvailable: (callback: (info: UpdateInfo) => void) => void;
-  onUpdateError: (callback: (error: string) => void) => void;
-  onUpdateDownloadProgress: (callback: (progress: DownloadProgress) => void) => void;
-  onUpdateDownloaded: (callback: (info: UpdateInfo) => void) => void;
-  removeUpdateListeners: () => void;
-  openExternal: (url: string) => Promise<{ success: boolean; error?: string }>;
-  getAudioPath: (audioFile: string) => Promise<string>;
-}
-
-declare global {
-  interface Window {
-    electronAPI?: ElectronAPI;
-  }
-}
+import type { UpdateInfo, DownloadProgress, UpdateChannel, Settings } from '@/types/electron';
 
 export function useElectronUpdater() {
   const [updateAvailable, setUpdateAvailable] = useState(false);
@@ -44,6 +11,7 @@ export function useElectronUpdater() {
   const [updateDownloaded, setUpdateDownloaded] = useState(false);
   const [error, setError] = useState<string | null>(null);
   const [currentVersion, setCurrentVersion] = useState<string>('');
+  const [settings, setSettings] = useState<Settings>({ updateChannel: 'stable', autoInstall: true });
 
   const isElectron = typeof window !== 'undefined' && window.electronAPI !== undefined;
 
@@ -53,6 +21,11 @@ export function useElectronUpdater() {
     const electronAPI = window.electronAPI!;
 
     electronAPI.getAppVersion().then(setCurrentVersion);
+    electronAPI.getSettings()
+      .then(setSettings)
+      .catch((err) => setError(err?.message || 'Failed to load settings'));
+
+    electronAPI.onSettingsChanged(setSettings);
 
     electronAPI.onUpdateChecking(() => {
       setChecking(true);
@@ -88,6 +61,7 @@ export function useElectronUpdater() {
 
     return () => {
       electronAPI.removeUpdateListeners();
+      electronAPI.removeSettingsListeners();
     };
   }, [isElectron]);
 
@@ -134,6 +108,29 @@ export function useElectronUpdater() {
     window.electronAPI!.installUpdate();
   }, [isElectron]);
 
+  const updateSettings = useCallback(async (patch: Partial<Settings>) => {
+    if (!isElectron) {
+      console.warn('Not running in Electron environment');
+      return;
+    }
+
+    const result = await window.electronAPI!.updateSettings(patch);
+
+    if (result.success && result.settings) {
+      setSettings(result.settings);
+    } else {
+      setError(result.error || 'Failed to update settings');
+    }
+  }, [isElectron]);
+
+  const setUpdateChannel = useCallback((channel: UpdateChannel) => {
+    return updateSettings({ updateChannel: channel });
+  }, [updateSettings]);
+
+  const setAutoInstall = useCallback((enabled: boolean) => {
+    return updateSettings({ autoInstall: enabled });
+  }, [updateSettings]);
+
   const openExternal = useCallback(async (url: string) => {
     if (!isElectron) {
       window.open(url, '_blank');
@@ -154,6 +151,8 @@ export function useElectronUpdater() {
   return {
     isElectron,
     currentVersion,
+    updateChannel: settings.updateChannel,
+    autoInstall: settings.autoInstall,
     updateAvailable,
     updateInfo,
     checking,
@@ -164,6 +163,9 @@ export function useElectronUpdater() {
     checkForUpdates,
     downloadUpdate,
     installUpdate,
+    updateSettings,
+    setUpdateChannel,
+    setAutoInstall,
     openExternal,
     getAudioPath,
   };
@@ -11,11 +11,22 @@ export interface DownloadProgress {
   total: number;
 }
 
+export type UpdateChannel = 'stable' | 'beta';
+
+export interface Settings {
+  updateChannel: UpdateChannel;
+  autoInstall: boolean;
+}
+
 export interface ElectronAPI {
   checkForUpdates: () => Promise<{ success: boolean; updateInfo?: UpdateInfo; error?: string }>;
   downloadUpdate: () => Promise<{ success: boolean; error?: string }>;
   installUpdate: () => void;
   getAppVersion: () => Promise<string>;
+  getSettings: () => Promise<Settings>;
+  updateSettings: (patch: Partial<Settings>) => Promise<{ success: boolean; settings?: Settings; error?: string }>;
+  onSettingsChanged: (callback: (settings: Settings) => void) => void;
+  removeSettingsListeners: () => void;
 
   onUpdateChecking: (callback: () => void) => void;
   onUpdateAvailable: (callback: (info: UpdateInfo) => void) => void;